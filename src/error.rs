@@ -20,10 +20,33 @@ pub enum ManifestError {
     TitleLength(usize),
     #[error("APIVersion must be at least 100003, provided: {0}")]
     ApiMinimumVersion(u32),
+    #[error("could not parse integer value for {0}: {1}")]
+    ParseInt(String, String),
+    #[error("invalid value for {0}: {1}")]
+    InvalidValue(String, String),
     #[error("error reading line")]
     ReadLineError(std::io::Error),
+    #[error("error writing manifest")]
+    WriteError(std::io::Error),
+    #[error("{0} depends on {1}, which is missing or does not satisfy the required version")]
+    UnsatisfiedDependency(String, String),
+    #[error("dependency cycle detected involving: {0}")]
+    DependencyCycle(String),
     #[error("unknown manifest error")]
     Unknown,
 }
 
 pub type Result<T, E = ManifestError> = std::result::Result<T, E>;
+
+// `ReadLineError` wraps a `std::io::Error`, which has no `serde::Serialize` impl, so the
+// derive macro can't be used here. Errors are diagnostic output, not meant to round-trip, so
+// we serialize the variant's display message rather than its structure.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ManifestError {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
@@ -4,15 +4,13 @@
 //! ```rust
 //! use eso_addon_manifest::{AddonManifestParser, AddonManifest};
 //!
-//! // let's say you have some addon with the patrial manifest:
-//! // ## Title: AddonName
-//! // ## APIVersion: 101037
-//! // [...]
+//! // let's say you have some addon with the partial manifest:
+//! let manifest = "## Title: AddonName\n## APIVersion: 101037\n";
 //!
 //! let parser = AddonManifestParser::default();
-//! let result: AddonManifest = parser.parse("some/file/path/AddonName.txt".to_string());
+//! let result: AddonManifest = parser.parse_str(manifest, false).unwrap();
 //! assert_eq!("AddonName".to_string(), result.title);
-//! assert_eq!(101037, result.addon_version);
+//! assert_eq!(101037, result.api_version);
 //! ```
 #![warn(
     missing_docs,
@@ -21,8 +19,12 @@
     broken_intra_doc_links
 )]
 mod error;
+mod resolve;
+
+pub use resolve::{resolve, ResolutionResult};
 
 use std::{
+    collections::BTreeMap,
     fs::File,
     io::{BufRead, BufReader},
 };
@@ -31,7 +33,7 @@ use error::{ManifestError, Result};
 use regex::Regex;
 
 static RE_DIRECTIVE: &str = r#"^## (?P<directive>.*): (?P<value>.*)"#;
-static RE_DEPENDS: &str = r#"^(?P<name>.+?)(([<=>]+)(?P<version>.*))?$"#;
+static RE_DEPENDS: &str = r#"^(?P<name>.+?)((?P<op>[<=>]+)(?P<version>.*))?$"#;
 
 enum LineType {
     Directive,
@@ -54,19 +56,135 @@ impl LineType {
     }
 }
 
+/// A comparison operator and version captured from a dependency entry (e.g. `>=20`), used to
+/// check whether an installed addon's version satisfies what the dependent addon requires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VersionConstraint {
+    /// `=` or `==`, the installed version must equal the given value
+    Eq(u32),
+    /// `>=`, the installed version must be greater than or equal to the given value
+    Gte(u32),
+    /// `<=`, the installed version must be less than or equal to the given value
+    Lte(u32),
+    /// `>`, the installed version must be strictly greater than the given value
+    Gt(u32),
+    /// `<`, the installed version must be strictly less than the given value
+    Lt(u32),
+}
+impl VersionConstraint {
+    fn from_op(op: &str, version: u32) -> Option<Self> {
+        match op {
+            "=" | "==" => Some(Self::Eq(version)),
+            ">=" => Some(Self::Gte(version)),
+            "<=" => Some(Self::Lte(version)),
+            ">" => Some(Self::Gt(version)),
+            "<" => Some(Self::Lt(version)),
+            _ => None,
+        }
+    }
+}
+
 /// AddOn Depenency data
 #[derive(Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DependsEntry {
     /// Dependent addon title
     pub title: String,
     /// Optional dependent addon version
     pub version: Option<u32>,
+    /// Optional comparison operator captured alongside `version` (e.g. `>=` in `LibLibrary>=20`)
+    pub constraint: Option<VersionConstraint>,
+}
+impl DependsEntry {
+    /// Returns true if `installed` satisfies this dependency's version constraint. When no
+    /// constraint is present, any installed version satisfies the dependency.
+    pub fn satisfies(&self, installed: u32) -> bool {
+        match self.constraint {
+            None => true,
+            Some(VersionConstraint::Eq(v)) => installed == v,
+            Some(VersionConstraint::Gte(v)) => installed >= v,
+            Some(VersionConstraint::Lte(v)) => installed <= v,
+            Some(VersionConstraint::Gt(v)) => installed > v,
+            Some(VersionConstraint::Lt(v)) => installed < v,
+        }
+    }
+}
+
+/// A structured, comparable rendering of the `Version` directive (e.g. `2.0.2`), parsed
+/// leniently so forms like `2.0`, `v1.2.3`, and `1.2.3-beta` are all accepted. Kept alongside
+/// the raw `version` string so the original text survives round-tripping while callers gain
+/// ordering for "is this release newer" checks.
+///
+/// Note: the pre-release suffix (`pre`) is compared byte-wise via `String`'s `Ord`, not as
+/// numeric segments, so e.g. `1.0.0-alpha10` sorts *before* `1.0.0-alpha2`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SemVer {
+    /// Major version component
+    pub major: u32,
+    /// Minor version component, defaulted to 0 when omitted (e.g. `2.0` -> `2.0.0`)
+    pub minor: u32,
+    /// Patch version component, defaulted to 0 when omitted
+    pub patch: u32,
+    /// Pre-release identifier following a `-`, if any (e.g. `beta` in `1.2.3-beta`)
+    pub pre: Option<String>,
+}
+impl SemVer {
+    /// Leniently parse `s` into a [`SemVer`], tolerating a leading `v`/`V`, a missing minor or
+    /// patch component, and a `-`-delimited pre-release suffix. Returns `None` if `s` doesn't
+    /// look like a semantic version at all.
+    pub fn parse(s: &str) -> Option<Self> {
+        let trimmed = s.trim();
+        let trimmed = trimmed
+            .strip_prefix('v')
+            .or_else(|| trimmed.strip_prefix('V'))
+            .unwrap_or(trimmed);
+        let (core, pre) = match trimmed.split_once('-') {
+            Some((core, pre)) => (core, Some(pre.to_string())),
+            None => (trimmed, None),
+        };
+
+        let mut parts = core.split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next().map_or(Ok(0), str::parse).ok()?;
+        let patch = parts.next().map_or(Ok(0), str::parse).ok()?;
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(Self {
+            major,
+            minor,
+            patch,
+            pre,
+        })
+    }
+}
+impl Ord for SemVer {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (&self.pre, &other.pre) {
+                (None, None) => std::cmp::Ordering::Equal,
+                // a pre-release is lower precedence than the release it precedes
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (Some(a), Some(b)) => a.cmp(b),
+            })
+    }
+}
+impl PartialOrd for SemVer {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 /// Manifest file data store
 ///
 /// Validation data provided by: [ESOUI Wiki](https://wiki.esoui.com/Addon_manifest_(.txt)_format)
 #[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddonManifest {
     /// Addon title, a character string for human display (e.g. SkyShards)
     pub title: String,
@@ -80,15 +198,38 @@ pub struct AddonManifest {
     pub addon_version: Option<u32>,
     /// A version identifier for ESOUI and/or Minion (e.g. 2.0.2) to separate add-on releases and/or updates
     pub version: Option<String>,
+    /// Structured, comparable parse of `version`, when it looks like a semantic version.
+    /// `None` if `version` is absent or couldn't be parsed leniently; the raw text is always
+    /// kept in `version` regardless.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub version_semver: Option<SemVer>,
     /// A space separated name list of add-ons/libraries that your add-on needs to run correctly (e.g. LibAddonMenu-2.0 LibDialog). If any addon/library in this line is missing your adon won't load!
+    #[cfg_attr(feature = "serde", serde(default))]
     pub depends_on: Vec<DependsEntry>,
     /// A name list similar to DependsOn: but the add-ons in this list will not prevent your add-on from running. Use this to assure other addons listed here are loaded before your addon (e.g. AddonName1 AddonName2).
+    #[cfg_attr(feature = "serde", serde(default))]
     pub optional_depends_on: Vec<DependsEntry>,
     /// false or not present : if this add-on is not a library or support add-on; true : if this add-on is a library or support add-on
     pub is_library: Option<bool>,
+    /// Directives this crate doesn't otherwise model (e.g. `SavedVariables`, `Credits`,
+    /// `Contributors`, `Description`), keyed by directive name. Values are appended in file
+    /// order, since some directives (e.g. `SavedVariables`) legitimately repeat.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "BTreeMap::is_empty", default)
+    )]
+    pub extras: BTreeMap<String, Vec<String>>,
     /// Vec of errors produced during import or full validation
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Vec::is_empty", skip_deserializing, default)
+    )]
     pub errors: Vec<ManifestError>,
     /// Vec of warnings (stored as error type) during import or full validation
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Vec::is_empty", skip_deserializing, default)
+    )]
     pub warnings: Vec<ManifestError>,
 }
 impl PartialEq for AddonManifest {
@@ -99,11 +240,81 @@ impl PartialEq for AddonManifest {
             && self.api_version_2 == other.api_version_2
             && self.addon_version == other.addon_version
             && self.version == other.version
+            && self.version_semver == other.version_semver
             && self.depends_on == other.depends_on
             && self.optional_depends_on == other.optional_depends_on
             && self.is_library == other.is_library
+            && self.extras == other.extras
+    }
+}
+impl std::fmt::Display for AddonManifest {
+    /// Renders this manifest back into canonical `## Directive: value` lines, reconstructing
+    /// `APIVersion: 100026 100027` when `api_version_2` is set and re-emitting
+    /// `DependsOn`/`OptionalDependsOn` entries with their operators and versions.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "## Title: {}", self.title)?;
+        writeln!(f, "## Author: {}", self.author)?;
+        match self.api_version_2 {
+            Some(api_version_2) => {
+                writeln!(f, "## APIVersion: {} {}", self.api_version, api_version_2)?
+            }
+            None => writeln!(f, "## APIVersion: {}", self.api_version)?,
+        }
+        if let Some(addon_version) = self.addon_version {
+            writeln!(f, "## AddOnVersion: {}", addon_version)?;
+        }
+        if let Some(version) = &self.version {
+            writeln!(f, "## Version: {}", version)?;
+        }
+        if !self.depends_on.is_empty() {
+            writeln!(f, "## DependsOn: {}", format_depends(&self.depends_on))?;
+        }
+        if !self.optional_depends_on.is_empty() {
+            writeln!(
+                f,
+                "## OptionalDependsOn: {}",
+                format_depends(&self.optional_depends_on)
+            )?;
+        }
+        if let Some(is_library) = self.is_library {
+            writeln!(f, "## IsLibrary: {}", is_library)?;
+        }
+        for (directive, values) in &self.extras {
+            for value in values {
+                writeln!(f, "## {}: {}", directive, value)?;
+            }
+        }
+        Ok(())
     }
 }
+impl AddonManifest {
+    /// Writes this manifest back out to `w` in canonical `Manifest.txt` form. Pair with
+    /// [`AddonManifest::to_string`] (via the blanket [`ToString`] impl from [`std::fmt::Display`])
+    /// for an in-memory rendering, enabling a parse → modify → write round-trip.
+    pub fn write<W: std::io::Write>(&self, w: &mut W) -> Result<()> {
+        write!(w, "{}", self).map_err(ManifestError::WriteError)
+    }
+}
+
+fn format_depends(entries: &[DependsEntry]) -> String {
+    entries
+        .iter()
+        .map(|entry| match (entry.constraint, entry.version) {
+            (Some(VersionConstraint::Eq(v)), _) => format!("{}={}", entry.title, v),
+            (Some(VersionConstraint::Gte(v)), _) => format!("{}>={}", entry.title, v),
+            (Some(VersionConstraint::Lte(v)), _) => format!("{}<={}", entry.title, v),
+            (Some(VersionConstraint::Gt(v)), _) => format!("{}>{}", entry.title, v),
+            (Some(VersionConstraint::Lt(v)), _) => format!("{}<{}", entry.title, v),
+            // An operator that didn't map to a known `VersionConstraint` (e.g. `<>`) still
+            // carries a parsed version number. Re-emit it behind an operator that `from_op`
+            // also fails to recognize so the round-trip reproduces the same `None`
+            // constraint instead of silently dropping the version.
+            (None, Some(v)) => format!("{}<>{}", entry.title, v),
+            (None, None) => entry.title.clone(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
 
 /// Parser helper struct
 #[derive(Debug)]
@@ -122,11 +333,22 @@ impl Default for AddonManifestParser {
 
 impl AddonManifestParser {
     /// Parse a given file path into an AddonManifest result
-    pub fn parse(&mut self, path: String, full_validate: Option<bool>) -> Result<AddonManifest> {
+    pub fn parse(&self, path: String, full_validate: Option<bool>) -> Result<AddonManifest> {
         let full_validate = full_validate.unwrap_or_default();
-        let file = File::open(path).unwrap();
-        let reader = BufReader::new(file);
+        let file = File::open(path).map_err(ManifestError::ReadLineError)?;
+        self.parse_reader(BufReader::new(file), full_validate)
+    }
+
+    /// Parse a manifest already held in memory as a string
+    pub fn parse_str(&self, s: &str, full_validate: bool) -> Result<AddonManifest> {
+        self.parse_reader(s.as_bytes(), full_validate)
+    }
 
+    /// Parse a manifest from any buffered reader, never panicking: a malformed line or value
+    /// is recorded as a [`ManifestError`] in the returned [`AddonManifest::errors`] instead of
+    /// aborting the parse. A failure to read a line from `r` (including a line that is not
+    /// valid UTF-8) ends the parse with an `Err`.
+    pub fn parse_reader<R: BufRead>(&self, r: R, full_validate: bool) -> Result<AddonManifest> {
         let mut result = AddonManifest {
             title: "".to_string(),
             author: "".to_string(),
@@ -134,8 +356,8 @@ impl AddonManifestParser {
             ..Default::default()
         };
 
-        for line in reader.lines() {
-            let line = line.map_err(ManifestError::ReadLineError).unwrap();
+        for line in r.lines() {
+            let line = line.map_err(ManifestError::ReadLineError)?;
             self.parse_line(line, &mut result, full_validate);
         }
 
@@ -156,9 +378,6 @@ impl AddonManifestParser {
                     .push(ManifestError::ApiMinimumVersion(result.api_version))
             }
         }
-        if !result.errors.is_empty() {
-            // return Err(result);
-        }
         Ok(result)
     }
 
@@ -205,36 +424,79 @@ impl AddonManifestParser {
                             "APIVersion" => {
                                 if value.contains(' ') {
                                     // we have to suppported version
-                                    let values: Vec<u32> =
-                                        value.split(' ').map(|x| x.parse().unwrap()).collect();
-                                    result.api_version = values[0];
-                                    result.api_version_2 = Some(values[1]);
+                                    let values: Vec<&str> = value.split(' ').collect();
+                                    match values[0].parse() {
+                                        Ok(v) => result.api_version = v,
+                                        Err(_) => result.errors.push(ManifestError::ParseInt(
+                                            directive.to_string(),
+                                            values[0].to_string(),
+                                        )),
+                                    }
+                                    match values.get(1) {
+                                        Some(v2) => match v2.parse() {
+                                            Ok(v) => result.api_version_2 = Some(v),
+                                            Err(_) => result.errors.push(ManifestError::ParseInt(
+                                                directive.to_string(),
+                                                v2.to_string(),
+                                            )),
+                                        },
+                                        None => result.errors.push(ManifestError::InvalidValue(
+                                            directive.to_string(),
+                                            value.to_string(),
+                                        )),
+                                    }
                                 } else {
-                                    result.api_version = value.parse().unwrap();
+                                    match value.parse() {
+                                        Ok(v) => result.api_version = v,
+                                        Err(_) => result.errors.push(ManifestError::ParseInt(
+                                            directive.to_string(),
+                                            value.to_string(),
+                                        )),
+                                    }
                                 }
                             }
-                            "AddOnVersion" => {
-                                result.addon_version = Some(value.parse().unwrap());
-                            }
+                            "AddOnVersion" => match value.parse() {
+                                Ok(v) => result.addon_version = Some(v),
+                                Err(_) => result.errors.push(ManifestError::ParseInt(
+                                    directive.to_string(),
+                                    value.to_string(),
+                                )),
+                            },
                             "Version" => {
                                 result.version = Some(value.to_string());
+                                match SemVer::parse(value) {
+                                    Some(semver) => result.version_semver = Some(semver),
+                                    None => result.warnings.push(ManifestError::InvalidValue(
+                                        directive.to_string(),
+                                        value.to_string(),
+                                    )),
+                                }
                             }
                             "DependsOn" => {
-                                let depends = self.parse_depends(value);
+                                let depends = self.parse_depends(value, &mut result.errors);
                                 result.depends_on.extend(depends);
                             }
                             "OptionalDependsOn" => {
-                                let depends = self.parse_depends(value);
+                                let depends = self.parse_depends(value, &mut result.errors);
                                 result.optional_depends_on.extend(depends);
                             }
-                            "IsLibrary" => {
-                                result.is_library = Some(value.parse().unwrap());
-                            }
+                            "IsLibrary" => match value.parse() {
+                                Ok(v) => result.is_library = Some(v),
+                                Err(_) => result.errors.push(ManifestError::InvalidValue(
+                                    directive.to_string(),
+                                    value.to_string(),
+                                )),
+                            },
                             _ => {
                                 // unmatched directives are not necessarily an error, see: Credits, Contributors, etc.
                                 result
                                     .warnings
                                     .push(ManifestError::UnmappedDirective(value.to_string()));
+                                result
+                                    .extras
+                                    .entry(directive.to_string())
+                                    .or_default()
+                                    .push(value.to_string());
                             }
                         }
                     }
@@ -258,7 +520,7 @@ impl AddonManifestParser {
         }
     }
 
-    fn parse_depends(&self, line: &str) -> Vec<DependsEntry> {
+    fn parse_depends(&self, line: &str, errors: &mut Vec<ManifestError>) -> Vec<DependsEntry> {
         let mut result = vec![];
         let values: Vec<&str> = line.split(' ').collect();
         for val in values.iter() {
@@ -271,7 +533,19 @@ impl AddonManifestParser {
                     // handle bad entry error
                 }
                 if let Some(version) = captures.name("version") {
-                    depends_entry.version = Some(version.as_str().parse().unwrap());
+                    match version.as_str().parse() {
+                        Ok(version_num) => {
+                            depends_entry.version = Some(version_num);
+                            if let Some(op) = captures.name("op") {
+                                depends_entry.constraint =
+                                    VersionConstraint::from_op(op.as_str(), version_num);
+                            }
+                        }
+                        Err(_) => errors.push(ManifestError::ParseInt(
+                            depends_entry.title.clone(),
+                            version.as_str().to_string(),
+                        )),
+                    }
                 }
 
                 if !depends_entry.title.is_empty() {
@@ -287,9 +561,11 @@ impl AddonManifestParser {
 
 #[cfg(test)]
 mod tests {
-    use std::vec;
+    use std::{collections::BTreeMap, vec};
 
-    use crate::{AddonManifest, AddonManifestParser, DependsEntry};
+    use crate::{
+        AddonManifest, AddonManifestParser, DependsEntry, ManifestError, SemVer, VersionConstraint,
+    };
 
     macro_rules! parse_depends_tests {
         ($($name:ident: $value:expr,)*) => {
@@ -298,7 +574,8 @@ mod tests {
                 fn $name() {
                     let (input, expected) = $value;
                     let parser = AddonManifestParser::default();
-                    let result = parser.parse_depends(input);
+                    let mut errors = vec![];
+                    let result = parser.parse_depends(input, &mut errors);
                     assert_eq!(expected, result);
                 }
             )*
@@ -332,15 +609,250 @@ mod tests {
         assert_ne!(
             DependsEntry {
                 title: "".to_string(),
-                version: Some(1)
+                version: Some(1),
+                constraint: None,
             },
             DependsEntry {
                 title: "".to_string(),
-                version: None
+                version: None,
+                constraint: None,
             }
         );
     }
 
+    #[test]
+    fn test_satisfies_no_constraint() {
+        let entry = DependsEntry {
+            title: "LibLibrary".to_string(),
+            version: None,
+            constraint: None,
+        };
+        assert!(entry.satisfies(0));
+        assert!(entry.satisfies(100));
+    }
+
+    #[test]
+    fn test_satisfies_constraint() {
+        let entry = DependsEntry {
+            title: "LibLibrary".to_string(),
+            version: Some(20),
+            constraint: Some(VersionConstraint::Gte(20)),
+        };
+        assert!(!entry.satisfies(19));
+        assert!(entry.satisfies(20));
+        assert!(entry.satisfies(21));
+    }
+
+    #[test]
+    fn test_semver_parse_lenient_forms() {
+        assert_eq!(
+            Some(SemVer {
+                major: 2,
+                minor: 0,
+                patch: 2,
+                pre: None,
+            }),
+            SemVer::parse("2.0.2")
+        );
+        assert_eq!(
+            Some(SemVer {
+                major: 2,
+                minor: 0,
+                patch: 0,
+                pre: None,
+            }),
+            SemVer::parse("2.0")
+        );
+        assert_eq!(
+            Some(SemVer {
+                major: 1,
+                minor: 2,
+                patch: 3,
+                pre: None,
+            }),
+            SemVer::parse("v1.2.3")
+        );
+        assert_eq!(
+            Some(SemVer {
+                major: 1,
+                minor: 2,
+                patch: 3,
+                pre: Some("beta".to_string()),
+            }),
+            SemVer::parse("1.2.3-beta")
+        );
+        assert_eq!(None, SemVer::parse("r5"));
+    }
+
+    #[test]
+    fn test_semver_ordering() {
+        assert!(SemVer::parse("1.2.3").unwrap() < SemVer::parse("1.3.0").unwrap());
+        assert!(SemVer::parse("1.2.3-beta").unwrap() < SemVer::parse("1.2.3").unwrap());
+    }
+
+    #[test]
+    fn test_parse_version_falls_back_to_raw_string_on_bad_semver() {
+        let parser = AddonManifestParser::default();
+        let result = parser
+            .parse_str("## Title: AddonName\n## Version: r5\n", false)
+            .unwrap();
+        assert_eq!(Some("r5".to_string()), result.version);
+        assert_eq!(None, result.version_semver);
+        assert_eq!(1, result.warnings.len());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let manifest = AddonManifest {
+            title: "LibLibrary".to_string(),
+            author: "TheAuthor".to_string(),
+            api_version: 101000,
+            api_version_2: Some(101001),
+            depends_on: vec![DependsEntry {
+                title: "CustomLib".to_string(),
+                version: Some(4),
+                constraint: Some(VersionConstraint::Gte(4)),
+            }],
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&manifest).unwrap();
+        let round_tripped: AddonManifest = serde_json::from_str(&json).unwrap();
+        assert_eq!(manifest, round_tripped);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_skips_empty_errors_and_warnings() {
+        let manifest = AddonManifest::default();
+        let json = serde_json::to_string(&manifest).unwrap();
+        assert!(!json.contains("errors"));
+        assert!(!json.contains("warnings"));
+    }
+
+    #[test]
+    fn test_write_round_trip() {
+        let manifest = AddonManifest {
+            title: "LibLibrary".to_string(),
+            author: "TheAuthor".to_string(),
+            api_version: 101000,
+            api_version_2: Some(101001),
+            addon_version: Some(27),
+            version: Some("1.20".to_string()),
+            version_semver: SemVer::parse("1.20"),
+            depends_on: vec![
+                DependsEntry {
+                    title: "CustomLib".to_string(),
+                    version: Some(4),
+                    constraint: Some(VersionConstraint::Gte(4)),
+                },
+                DependsEntry {
+                    title: "OtherLib".to_string(),
+                    version: None,
+                    constraint: None,
+                },
+            ],
+            is_library: Some(true),
+            ..Default::default()
+        };
+        let written = manifest.to_string();
+        assert_eq!(
+            "## Title: LibLibrary\n\
+             ## Author: TheAuthor\n\
+             ## APIVersion: 101000 101001\n\
+             ## AddOnVersion: 27\n\
+             ## Version: 1.20\n\
+             ## DependsOn: CustomLib>=4 OtherLib\n\
+             ## IsLibrary: true\n",
+            written
+        );
+
+        let parser = AddonManifestParser::default();
+        let result = parser.parse_str(&written, false).unwrap();
+        assert_eq!(manifest, result);
+    }
+
+    #[test]
+    fn test_write_round_trip_preserves_unrecognized_operator_version() {
+        let parser = AddonManifestParser::default();
+        let parsed = parser
+            .parse_str("## Title: AddonName\n## DependsOn: Lib<>5\n", false)
+            .unwrap();
+        assert_eq!(None, parsed.depends_on[0].constraint);
+        assert_eq!(Some(5), parsed.depends_on[0].version);
+
+        let written = parsed.to_string();
+        assert!(written.contains("## DependsOn: Lib<>5"));
+
+        let reparsed = parser.parse_str(&written, false).unwrap();
+        assert_eq!(parsed, reparsed);
+    }
+
+    #[test]
+    fn test_parse_str_bad_integer_pushes_error_instead_of_panicking() {
+        let parser = AddonManifestParser::default();
+        let result = parser
+            .parse_str("## Title: AddonName\n## APIVersion: notanumber\n", false)
+            .unwrap();
+        assert_eq!(0, result.api_version);
+        assert_eq!(1, result.errors.len());
+        assert!(matches!(
+            &result.errors[0],
+            ManifestError::ParseInt(directive, value)
+                if directive == "APIVersion" && value == "notanumber"
+        ));
+    }
+
+    #[test]
+    fn test_parse_missing_file_returns_error_instead_of_panicking() {
+        let parser = AddonManifestParser::default();
+        assert!(parser
+            .parse("no/such/path/Manifest.txt".to_string(), None)
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_captures_unmapped_directives_as_extras() {
+        let parser = AddonManifestParser::default();
+        let result = parser
+            .parse_str(
+                "## Title: AddonName\n\
+                 ## APIVersion: 101037\n\
+                 ## SavedVariables: AddonNameSaved\n\
+                 ## SavedVariables: AddonNameAccountSaved\n\
+                 ## Credits: SomeoneElse\n",
+                false,
+            )
+            .unwrap();
+        assert_eq!(
+            vec![
+                "AddonNameSaved".to_string(),
+                "AddonNameAccountSaved".to_string()
+            ],
+            result.extras["SavedVariables"]
+        );
+        assert_eq!(vec!["SomeoneElse".to_string()], result.extras["Credits"]);
+    }
+
+    #[test]
+    fn test_write_round_trip_preserves_extras() {
+        let manifest = AddonManifest {
+            title: "AddonName".to_string(),
+            api_version: 101037,
+            extras: BTreeMap::from([(
+                "SavedVariables".to_string(),
+                vec![
+                    "AddonNameSaved".to_string(),
+                    "AddonNameAccountSaved".to_string(),
+                ],
+            )]),
+            ..Default::default()
+        };
+        let parser = AddonManifestParser::default();
+        let result = parser.parse_str(&manifest.to_string(), false).unwrap();
+        assert_eq!(manifest, result);
+    }
+
     parse_depends_tests! {
         test_parse_depend_single: (
             "LibLibrary",
@@ -348,6 +860,7 @@ mod tests {
                 DependsEntry {
                     title: "LibLibrary".to_string(),
                     version: None,
+                    constraint: None,
             }]),
         test_parse_depend_multiple: (
             "LibLibrary LibOther",
@@ -355,10 +868,12 @@ mod tests {
                 DependsEntry {
                     title: "LibLibrary".to_string(),
                     version: None,
+                    constraint: None,
                 },
                 DependsEntry {
                     title: "LibOther".to_string(),
                     version: None,
+                    constraint: None,
                 },
             ]),
         test_parse_depend_version: (
@@ -367,6 +882,7 @@ mod tests {
                 DependsEntry {
                     title: "LibLibrary".to_string(),
                     version: Some(20),
+                    constraint: Some(VersionConstraint::Gte(20)),
             }]),
         test_parse_depend_version_multiple: (
             "LibLibrary>=10 CustomAddon LibOther<=5",
@@ -374,14 +890,17 @@ mod tests {
                 DependsEntry {
                     title: "LibLibrary".to_string(),
                     version: Some(10),
+                    constraint: Some(VersionConstraint::Gte(10)),
                 },
                 DependsEntry {
                     title: "CustomAddon".to_string(),
                     version: None,
+                    constraint: None,
                 },
                 DependsEntry {
                     title: "LibOther".to_string(),
                     version: Some(5),
+                    constraint: Some(VersionConstraint::Lte(5)),
                 },
             ]),
     }
@@ -400,6 +919,7 @@ mod tests {
                 depends_on: vec![DependsEntry {
                     title: "LibLibrary".to_string(),
                     version: None,
+                    constraint: None,
                 }],
                 ..Default::default()
             }
@@ -418,14 +938,17 @@ mod tests {
                 depends_on: vec![
                     DependsEntry {
                         title: "CustomLib".to_string(),
-                        version: Some(4)
+                        version: Some(4),
+                        constraint: Some(VersionConstraint::Gte(4)),
                     },
                     DependsEntry {
                         title: "OtherLib".to_string(),
-                        version: None
+                        version: None,
+                        constraint: None,
                     }
                 ],
                 version: Some("1.20".to_string()),
+                version_semver: SemVer::parse("1.20"),
                 addon_version: Some(27),
                 api_version: 101000,
                 api_version_2: Some(101001),
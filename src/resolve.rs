@@ -0,0 +1,241 @@
+//! Cross-manifest dependency resolution over a collection of parsed [`AddonManifest`]s, so
+//! callers can tell which hard dependencies are missing and in what order addons must load.
+
+use std::collections::BTreeMap;
+
+use crate::{AddonManifest, DependsEntry, ManifestError};
+
+/// The outcome of resolving `depends_on`/`optional_depends_on` across a set of manifests.
+#[derive(Debug, Default)]
+pub struct ResolutionResult {
+    /// Addon titles in the order they must load so every dependency loads before its dependent.
+    /// Left empty only if the `depends_on` edges alone form a cycle; when a cycle needs an
+    /// `optional_depends_on` edge, all optional edges are ignored for ordering and every addon
+    /// is still ordered.
+    pub load_order: Vec<String>,
+    /// Unsatisfied `depends_on` entries: the addon is missing, or its installed version does
+    /// not satisfy the declared [`crate::VersionConstraint`]
+    pub errors: Vec<ManifestError>,
+    /// Unsatisfied `optional_depends_on` entries, same checks as `errors` but non-fatal
+    pub warnings: Vec<ManifestError>,
+}
+
+/// Resolve dependencies across `manifests`, keyed by [`AddonManifest::title`]. Produces a
+/// topological load order and reports unsatisfied hard dependencies as errors, unsatisfied
+/// optional dependencies as warnings, and a *hard* dependency cycle as an error with an empty
+/// load order. A cycle formed only from optional dependencies is non-fatal: ordering falls back
+/// to ignoring those edges rather than failing the whole collection.
+pub fn resolve(manifests: &[AddonManifest]) -> ResolutionResult {
+    let by_title: BTreeMap<&str, &AddonManifest> =
+        manifests.iter().map(|m| (m.title.as_str(), m)).collect();
+
+    let mut result = ResolutionResult::default();
+    for manifest in manifests {
+        for dep in &manifest.depends_on {
+            check_dependency(manifest, dep, &by_title, &mut result.errors);
+        }
+        for dep in &manifest.optional_depends_on {
+            check_dependency(manifest, dep, &by_title, &mut result.warnings);
+        }
+    }
+
+    match topological_order(manifests, &by_title) {
+        Ok(order) => result.load_order = order,
+        Err(cycle) => result.errors.push(ManifestError::DependencyCycle(cycle)),
+    }
+
+    result
+}
+
+fn check_dependency(
+    manifest: &AddonManifest,
+    dep: &DependsEntry,
+    by_title: &BTreeMap<&str, &AddonManifest>,
+    out: &mut Vec<ManifestError>,
+) {
+    let satisfied = by_title
+        .get(dep.title.as_str())
+        .is_some_and(|installed| dep.satisfies(installed.addon_version.unwrap_or(0)));
+    if !satisfied {
+        out.push(ManifestError::UnsatisfiedDependency(
+            manifest.title.clone(),
+            dep.title.clone(),
+        ));
+    }
+}
+
+/// Kahn's algorithm over the `depends_on`/`optional_depends_on` edges restricted to addons
+/// present in `by_title`; unknown dependencies were already reported by `check_dependency` and
+/// don't participate in ordering. Tries both edge sets first so optional dependencies still
+/// influence ordering when possible; if that has a cycle, falls back to `depends_on` edges only,
+/// since a cycle formed purely out of optional (non-fatal) dependencies must not be able to wipe
+/// out ordering for the whole collection. On failure, returns the titles of every addon that
+/// could not be ordered (the cycle's members and any addon depending on them), joined.
+fn topological_order(
+    manifests: &[AddonManifest],
+    by_title: &BTreeMap<&str, &AddonManifest>,
+) -> std::result::Result<Vec<String>, String> {
+    kahn_order(manifests, by_title, true).or_else(|_| kahn_order(manifests, by_title, false))
+}
+
+fn kahn_order(
+    manifests: &[AddonManifest],
+    by_title: &BTreeMap<&str, &AddonManifest>,
+    include_optional: bool,
+) -> std::result::Result<Vec<String>, String> {
+    let mut in_degree: BTreeMap<&str, usize> = by_title.keys().map(|&title| (title, 0)).collect();
+    let mut dependents: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+
+    for manifest in manifests {
+        let deps: Box<dyn Iterator<Item = &DependsEntry>> = if include_optional {
+            Box::new(
+                manifest
+                    .depends_on
+                    .iter()
+                    .chain(&manifest.optional_depends_on),
+            )
+        } else {
+            Box::new(manifest.depends_on.iter())
+        };
+        for dep in deps {
+            if by_title.contains_key(dep.title.as_str()) {
+                *in_degree.get_mut(manifest.title.as_str()).unwrap() += 1;
+                dependents
+                    .entry(dep.title.as_str())
+                    .or_default()
+                    .push(manifest.title.as_str());
+            }
+        }
+    }
+
+    let mut ready: Vec<&str> = in_degree
+        .iter()
+        .filter(|(_, &degree)| degree == 0)
+        .map(|(&title, _)| title)
+        .collect();
+    ready.sort_unstable();
+
+    let mut order = Vec::with_capacity(in_degree.len());
+    while let Some(title) = ready.pop() {
+        order.push(title.to_string());
+        if let Some(unblocked) = dependents.get(title) {
+            for &dependent in unblocked {
+                let degree = in_degree.get_mut(dependent).unwrap();
+                *degree -= 1;
+                if *degree == 0 {
+                    ready.push(dependent);
+                }
+            }
+        }
+        ready.sort_unstable();
+    }
+
+    if order.len() != in_degree.len() {
+        let loaded: std::collections::HashSet<&str> = order.iter().map(String::as_str).collect();
+        let cycle: Vec<&str> = in_degree
+            .keys()
+            .filter(|title| !loaded.contains(*title))
+            .copied()
+            .collect();
+        return Err(cycle.join(", "));
+    }
+
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolve;
+    use crate::{AddonManifest, DependsEntry, VersionConstraint};
+
+    fn manifest(
+        title: &str,
+        addon_version: Option<u32>,
+        depends_on: Vec<DependsEntry>,
+    ) -> AddonManifest {
+        AddonManifest {
+            title: title.to_string(),
+            addon_version,
+            depends_on,
+            ..Default::default()
+        }
+    }
+
+    fn depends(title: &str, constraint: Option<VersionConstraint>) -> DependsEntry {
+        DependsEntry {
+            title: title.to_string(),
+            version: None,
+            constraint,
+        }
+    }
+
+    #[test]
+    fn test_resolve_load_order() {
+        let lib = manifest("LibLibrary", Some(20), vec![]);
+        let addon = manifest("AddonName", None, vec![depends("LibLibrary", None)]);
+        let result = resolve(&[addon, lib]);
+        assert!(result.errors.is_empty());
+        assert_eq!(
+            vec!["LibLibrary".to_string(), "AddonName".to_string()],
+            result.load_order
+        );
+    }
+
+    #[test]
+    fn test_resolve_unsatisfied_dependency_version() {
+        let lib = manifest("LibLibrary", Some(10), vec![]);
+        let addon = manifest(
+            "AddonName",
+            None,
+            vec![depends("LibLibrary", Some(VersionConstraint::Gte(20)))],
+        );
+        let result = resolve(&[addon, lib]);
+        assert_eq!(1, result.errors.len());
+        assert!(matches!(
+            &result.errors[0],
+            crate::ManifestError::UnsatisfiedDependency(addon, dep)
+                if addon == "AddonName" && dep == "LibLibrary"
+        ));
+    }
+
+    #[test]
+    fn test_resolve_missing_dependency() {
+        let addon = manifest("AddonName", None, vec![depends("LibMissing", None)]);
+        let result = resolve(&[addon]);
+        assert_eq!(1, result.errors.len());
+    }
+
+    #[test]
+    fn test_resolve_detects_cycle() {
+        let a = manifest("A", None, vec![depends("B", None)]);
+        let b = manifest("B", None, vec![depends("A", None)]);
+        let result = resolve(&[a, b]);
+        assert!(result.load_order.is_empty());
+        assert!(result
+            .errors
+            .iter()
+            .any(|e| matches!(e, crate::ManifestError::DependencyCycle(_))));
+    }
+
+    #[test]
+    fn test_resolve_optional_only_cycle_does_not_empty_load_order() {
+        let a = AddonManifest {
+            title: "A".to_string(),
+            optional_depends_on: vec![depends("B", None)],
+            ..Default::default()
+        };
+        let b = AddonManifest {
+            title: "B".to_string(),
+            optional_depends_on: vec![depends("A", None)],
+            ..Default::default()
+        };
+        let unrelated = manifest("Unrelated", None, vec![]);
+        let result = resolve(&[a, b, unrelated]);
+        assert!(!result
+            .errors
+            .iter()
+            .any(|e| matches!(e, crate::ManifestError::DependencyCycle(_))));
+        assert_eq!(3, result.load_order.len());
+        assert!(result.load_order.contains(&"Unrelated".to_string()));
+    }
+}